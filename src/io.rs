@@ -1,21 +1,102 @@
 use bincode::Options;
 use serde::{de::DeserializeOwned, Serialize};
-use std::{error::Error, io::Read};
+use std::{fmt, io::Read, io::Write, str::Utf8Error};
 
 extern "C" {
     pub fn getchar() -> u32;
     pub fn putchar(c: u32) -> u32;
 }
 
+/// Error type for every tape operation. Lets a zkVM guest branch on the failure mode instead
+/// of pattern-matching opaque strings out of a `Box<dyn Error>`.
+#[derive(Debug)]
+pub enum TapeError {
+    /// The tape was drained before the current item was fully read.
+    UnexpectedEof,
+    /// A length prefix was larger than the caller's limit or remaining budget.
+    InvalidLength(usize),
+    /// A text field on the tape was not valid UTF-8.
+    Utf8(Utf8Error),
+    /// A value could not be parsed into its target type.
+    Parse,
+    /// bincode failed to encode or decode the object body.
+    Decode(bincode::Error),
+}
+
+impl fmt::Display for TapeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TapeError::UnexpectedEof => write!(f, "input tape ended unexpectedly"),
+            TapeError::InvalidLength(n) => write!(f, "invalid or over-long length prefix: {}", n),
+            TapeError::Utf8(e) => write!(f, "invalid UTF-8 on tape: {}", e),
+            TapeError::Parse => write!(f, "failed to parse tape value"),
+            TapeError::Decode(e) => write!(f, "failed to decode object body: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for TapeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            TapeError::Utf8(e) => Some(e),
+            TapeError::Decode(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<Utf8Error> for TapeError {
+    fn from(e: Utf8Error) -> Self {
+        TapeError::Utf8(e)
+    }
+}
+
+impl From<bincode::Error> for TapeError {
+    fn from(e: bincode::Error) -> Self {
+        TapeError::Decode(e)
+    }
+}
+
+impl From<std::io::Error> for TapeError {
+    fn from(_e: std::io::Error) -> Self {
+        // The only I/O source behind the tape is a drained reader, so a failure to fill a
+        // buffer means the tape ran out from under us.
+        TapeError::UnexpectedEof
+    }
+}
+
+/// Sentinel returned by `getchar` once the input tape is drained, matching C's convention of
+/// `getchar` yielding `-1` (here widened to `u32::MAX`) at end of file.
+pub const EOF: u32 = u32::MAX;
+
+/// Read a single byte off the input tape, returning `None` once the tape is exhausted.
+fn getchar_opt() -> Option<u8> {
+    let c = unsafe { getchar() };
+    if c == EOF {
+        None
+    } else {
+        Some(c as u8)
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 pub struct InputTape;
 
 impl Read for InputTape {
     fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
-        (0..buf.len()).for_each(|i| {
-            buf[i] = unsafe { getchar() as u8 };
-        });
-        Ok(buf.len())
+        // Honor the `Read` contract: fill as much as the tape has, and report a short read
+        // (`Ok(0)` at the very end) so combinators like `read_to_end` terminate cleanly.
+        let mut filled = 0;
+        for slot in buf.iter_mut() {
+            match getchar_opt() {
+                Some(byte) => {
+                    *slot = byte;
+                    filled += 1;
+                }
+                None => break,
+            }
+        }
+        Ok(filled)
     }
 }
 
@@ -31,6 +112,86 @@ impl OutputTape {
     }
 }
 
+impl Write for OutputTape {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        OutputTape::write(self, buf)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        // The tape is unbuffered: every byte is a `putchar` cycle as it is written.
+        Ok(())
+    }
+}
+
+/// Selects how the length prefix in front of a serialized object is framed on the tape.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FramingMode {
+    /// Legacy framing: the byte count as decimal ASCII digits followed by a `\n`.
+    Decimal,
+    /// Base-128 LEB128 varint: 7 bits per byte, high bit set on all but the final byte.
+    Varint,
+}
+
+/// Default framing used by [`read`]/[`write`]. Stays [`FramingMode::Decimal`] so the bare
+/// `read()` still decodes the decimal-framed tapes written before varint framing existed;
+/// varint framing (1-5 bytes instead of up-to-~20) is opt-in via [`read_framed`]/[`write_framed`]
+/// until producers have migrated.
+pub const DEFAULT_FRAMING: FramingMode = FramingMode::Decimal;
+
+/// Maximum number of bytes a LEB128 varint length prefix may occupy. A `u64` needs at most 10
+/// base-128 groups; anything longer is a corrupt or hostile prefix and is rejected before the
+/// accumulator shift can overflow.
+const MAX_VARINT_BYTES: usize = 10;
+
+/// Write a length as a base-128 LEB128 varint to the output tape.
+fn write_varint(mut n: usize) -> Result<(), TapeError> {
+    loop {
+        let mut byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n != 0 {
+            byte |= 0x80;
+        }
+        unsafe { putchar(byte as u32) };
+        if n == 0 {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Read a base-128 LEB128 varint length off the input tape.
+fn read_varint() -> Result<usize, TapeError> {
+    let mut value: usize = 0;
+    let mut shift = 0u32;
+    // A `usize` needs at most ceil(BITS / 7) base-128 groups: 10 on 64-bit, 5 on 32-bit targets.
+    let max_bytes = (usize::BITS as usize + 6) / 7;
+    for _ in 0..max_bytes {
+        let byte = getchar_opt().ok_or(TapeError::UnexpectedEof)?;
+        value |= ((byte & 0x7f) as usize) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+    // More than `max_bytes` continuation bytes: a corrupt prefix that would overrun the
+    // shift. Reject it rather than letting `shift` reach the width of `usize`.
+    Err(TapeError::InvalidLength(max_bytes))
+}
+
+/// Write the decimal-ASCII length prefix plus a trailing newline to the output tape.
+fn write_decimal_len(n: usize) -> Result<(), TapeError> {
+    let mut prefix = n.to_string().into_bytes();
+    prefix.push(b'\n');
+    write_vec(&prefix)
+}
+
+/// Read a decimal-ASCII length prefix terminated by a newline off the input tape.
+fn read_decimal_len() -> Result<usize, TapeError> {
+    let bytes = read_until(b'\n')?;
+    let s = std::str::from_utf8(&bytes)?;
+    s.parse().map_err(|_| TapeError::Parse)
+}
+
 /// Mimic std::io::println
 pub fn println(s: &str) {
     let length = s.len();
@@ -41,26 +202,21 @@ pub fn println(s: &str) {
     unsafe { putchar('\n' as u32) };
 }
 /// Reads a single line of input from stdin and returns it as a generic type T.
-pub fn read_line<T>() -> Result<T, Box<dyn Error>>
+pub fn read_line<T>() -> Result<T, TapeError>
 where
     T: std::str::FromStr,
-    <T as std::str::FromStr>::Err: std::error::Error + 'static
 {
     let input = read_until(b'\n')?;
     let trimmed = std::str::from_utf8(&input)?.trim();
-    match trimmed.parse() {
-        Ok(value) => Ok(value),
-        Err(e) => {
-            Err(Box::new(e))
-        }
-    }
+    trimmed.parse().map_err(|_| TapeError::Parse)
 }
 
-/// Read from the input tape until we hit a specific character.
-pub fn read_until(c: u8) -> Result<Vec<u8>, Box<dyn Error>> {
+/// Read from the input tape until we hit a specific character. Returns
+/// [`TapeError::UnexpectedEof`] if the tape drains before the target byte is seen.
+pub fn read_until(c: u8) -> Result<Vec<u8>, TapeError> {
     let mut result = Vec::new();
     loop {
-        let input = unsafe { getchar() as u8 };
+        let input = getchar_opt().ok_or(TapeError::UnexpectedEof)?;
         if input == c {
             // All done, found the character to stop at.
             break;
@@ -70,77 +226,555 @@ pub fn read_until(c: u8) -> Result<Vec<u8>, Box<dyn Error>> {
     Ok(result)
 }
 
-/// Read n bytes from the input tape.
-pub fn read_n(n: usize) -> Result<Vec<u8>, Box<dyn Error>> {
-    Ok((0..n).map(|_| unsafe { getchar() as u8 }).collect())
+/// Read n bytes from the input tape. Returns [`TapeError::UnexpectedEof`] if the tape drains
+/// before all `n` bytes are available.
+pub fn read_n(n: usize) -> Result<Vec<u8>, TapeError> {
+    let mut result = Vec::with_capacity(n);
+    for _ in 0..n {
+        result.push(getchar_opt().ok_or(TapeError::UnexpectedEof)?);
+    }
+    Ok(result)
 }
 
 /// Write the contents of a vector to the output tape.
-pub fn write_vec(v: impl AsRef<[u8]>) -> Result<(), Box<dyn Error>> {
+pub fn write_vec(v: impl AsRef<[u8]>) -> Result<(), TapeError> {
     v.as_ref().iter().for_each(|c| unsafe {
         putchar(*c as u32);
     });
     Ok(())
 }
 
-/// Construct a deserializable object from bytes read off the input tape.
-pub fn read<T: DeserializeOwned>() -> Result<T, Box<dyn Error>> {
-    // First line should be an integer specifying how many characters the serialized object takes
-    // up on the input tape.
-    let n: usize = match read_until(b'\n') {
-        Ok(bytes) => match std::str::from_utf8(&bytes) {
-            Ok(s) => match s.parse() {
-                Ok(num) => num,
-                Err(_) => {
-                    return Err(Box::new(std::io::Error::new(
-                        std::io::ErrorKind::Other,
-                        "Failed to parse input as usize",
-                    )));
-                }
-            },
-            Err(_) => {
-                return Err(Box::new(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Failed to convert input to UTF-8",
-                )));
-            }
-        },
-        Err(_) => {
-            return Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Failed to read input",
-            )));
+/// A running byte budget shared across a sequence of reads so that nested or streamed items
+/// cannot collectively overrun the limit even when each one is individually small. Modeled on
+/// bincode's `Bounded` limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct ReadBudget {
+    remaining: usize,
+}
+
+impl ReadBudget {
+    /// Create a budget allowing at most `max` bytes in total.
+    pub fn new(max: usize) -> Self {
+        ReadBudget { remaining: max }
+    }
+
+    /// Bytes still available before the budget is exhausted.
+    pub fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Charge `n` bytes against the budget, returning an error if it would overrun.
+    fn charge(&mut self, n: usize) -> Result<(), TapeError> {
+        if n > self.remaining {
+            return Err(TapeError::InvalidLength(n));
         }
+        self.remaining -= n;
+        Ok(())
+    }
+}
+
+/// Default ceiling, in bytes, that [`read`] will accept for a single length-delimited object
+/// before rejecting the prefix as an allocation bomb. 64 MiB comfortably fits any real VOPRF
+/// message while keeping a hostile tape from exhausting prover memory.
+pub const DEFAULT_READ_LIMIT: usize = 64 * 1024 * 1024;
+
+/// Construct a deserializable object from bytes read off the input tape, using
+/// [`DEFAULT_FRAMING`] for the length prefix and [`DEFAULT_READ_LIMIT`] as the size ceiling.
+pub fn read<T: DeserializeOwned>() -> Result<T, TapeError> {
+    read_limited(DEFAULT_READ_LIMIT)
+}
+
+/// Like [`read`], but rejects any length prefix greater than `max` before allocating, so a
+/// corrupt or hostile tape cannot force a giant `Vec` allocation.
+pub fn read_limited<T: DeserializeOwned>(max: usize) -> Result<T, TapeError> {
+    read_framed_limited(DEFAULT_FRAMING, max)
+}
+
+/// Construct a deserializable object from bytes read off the input tape, decoding the length
+/// prefix with the given [`FramingMode`]. Uses [`DEFAULT_READ_LIMIT`] as the size ceiling.
+pub fn read_framed<T: DeserializeOwned>(mode: FramingMode) -> Result<T, TapeError> {
+    read_framed_limited(mode, DEFAULT_READ_LIMIT)
+}
+
+/// Construct a deserializable object from bytes read off the input tape, decoding the length
+/// prefix with the given [`FramingMode`] and rejecting any length above `max`.
+pub fn read_framed_limited<T: DeserializeOwned>(
+    mode: FramingMode,
+    max: usize,
+) -> Result<T, TapeError> {
+    read_budgeted(mode, &mut ReadBudget::new(max))
+}
+
+/// Like [`read_framed_limited`], but charges the consumed bytes against a shared
+/// [`ReadBudget`] so a series of reads cannot collectively overrun the limit.
+pub fn read_budgeted<T: DeserializeOwned>(
+    mode: FramingMode,
+    budget: &mut ReadBudget,
+) -> Result<T, TapeError> {
+    // The prefix specifies how many bytes the serialized object takes up on the input tape.
+    let n = match mode {
+        FramingMode::Decimal => read_decimal_len()?,
+        FramingMode::Varint => read_varint()?,
     };
 
+    // Reject an over-long prefix before allocating anything for it.
+    budget.charge(n)?;
+
     // Now read the actual bytes relating to the serialized object.
-    let bytes = match read_n(n) {
-        Ok(b) => b,
-        Err(_) => {
-            return Err(Box::new(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                format!("Failed to read {} bytes", n),
-            )));
-        }
-    };
+    let bytes = read_n(n)?;
 
     // Deserialize the object.
     bincode::options()
         .with_big_endian()
         .deserialize(&bytes)
-        .map_err(|e| Box::new(e) as Box<dyn Error>)
+        .map_err(TapeError::Decode)
 }
 
-/// Serialize an object and write it to the output tape.
-pub fn write<T: Serialize>(value: &T) -> Result<(), Box<dyn Error>> {
+/// Serialize an object and write it to the output tape, using [`DEFAULT_FRAMING`] for the
+/// length prefix.
+pub fn write<T: Serialize>(value: &T) -> Result<(), TapeError> {
+    write_framed(value, DEFAULT_FRAMING)
+}
+
+/// Serialize an object and write it to the output tape, encoding the length prefix with the
+/// given [`FramingMode`].
+pub fn write_framed<T: Serialize>(value: &T, mode: FramingMode) -> Result<(), TapeError> {
     // Serialize the object to discover how many bytes it will take.
     let bytes = bincode::options().with_big_endian().serialize(value)?;
-    // Write an integer specifying the number of bytes used for the serialized object, plus a
-    // newline.
-    let mut n = bytes.len().to_string().into_bytes();
-    n.push(b'\n');
-    write_vec(&n)?;
+    // Write the length prefix according to the selected framing.
+    match mode {
+        FramingMode::Decimal => write_decimal_len(bytes.len())?,
+        FramingMode::Varint => write_varint(bytes.len())?,
+    }
     // Write the serialized object to the output tape.
     write_vec(&bytes)?;
     Ok(())
 }
+
+/// A type that can stream itself onto any [`Write`] sink without first buffering the whole
+/// encoding in memory. In the spirit of rust-lightning's `ser.rs` and mugle's `Writeable`.
+pub trait Writeable {
+    /// Encode `self` directly to `w`.
+    fn write<W: Write>(&self, w: &mut W) -> Result<(), TapeError>;
+}
+
+/// A type that can be reconstructed from any [`Read`] source.
+pub trait Readable: Sized {
+    /// Decode a value from `r`.
+    fn read<R: Read>(r: &mut R) -> Result<Self, TapeError>;
+}
+
+/// Write a base-128 LEB128 varint to an arbitrary [`Write`] sink.
+fn write_varint_to<W: Write>(w: &mut W, mut n: u64) -> Result<(), TapeError> {
+    loop {
+        let mut byte = (n & 0x7f) as u8;
+        n >>= 7;
+        if n != 0 {
+            byte |= 0x80;
+        }
+        w.write_all(&[byte])?;
+        if n == 0 {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// Read a base-128 LEB128 varint from an arbitrary [`Read`] source.
+fn read_varint_from<R: Read>(r: &mut R) -> Result<u64, TapeError> {
+    let mut value: u64 = 0;
+    let mut shift = 0u32;
+    for _ in 0..MAX_VARINT_BYTES {
+        let mut byte = [0u8; 1];
+        r.read_exact(&mut byte)?;
+        value |= ((byte[0] & 0x7f) as u64) << shift;
+        if byte[0] & 0x80 == 0 {
+            return Ok(value);
+        }
+        shift += 7;
+    }
+    // A varint spanning more than `MAX_VARINT_BYTES` bytes is malformed and would overrun the
+    // shift; reject it before the crafted record can panic the parser.
+    Err(TapeError::InvalidLength(MAX_VARINT_BYTES))
+}
+
+/// Varint-framed lengths are used throughout, so `usize` carries itself as a LEB128 value.
+impl Writeable for usize {
+    fn write<W: Write>(&self, w: &mut W) -> Result<(), TapeError> {
+        write_varint_to(w, *self as u64)
+    }
+}
+
+impl Readable for usize {
+    fn read<R: Read>(r: &mut R) -> Result<Self, TapeError> {
+        Ok(read_varint_from(r)? as usize)
+    }
+}
+
+impl Writeable for u64 {
+    fn write<W: Write>(&self, w: &mut W) -> Result<(), TapeError> {
+        w.write_all(&self.to_be_bytes())?;
+        Ok(())
+    }
+}
+
+impl Readable for u64 {
+    fn read<R: Read>(r: &mut R) -> Result<Self, TapeError> {
+        let mut buf = [0u8; 8];
+        r.read_exact(&mut buf)?;
+        Ok(u64::from_be_bytes(buf))
+    }
+}
+
+impl Writeable for u8 {
+    fn write<W: Write>(&self, w: &mut W) -> Result<(), TapeError> {
+        w.write_all(&[*self])?;
+        Ok(())
+    }
+}
+
+impl Readable for u8 {
+    fn read<R: Read>(r: &mut R) -> Result<Self, TapeError> {
+        let mut buf = [0u8; 1];
+        r.read_exact(&mut buf)?;
+        Ok(buf[0])
+    }
+}
+
+/// Byte blobs carry a varint length prefix followed by their raw bytes.
+impl Writeable for Vec<u8> {
+    fn write<W: Write>(&self, w: &mut W) -> Result<(), TapeError> {
+        write_varint_to(w, self.len() as u64)?;
+        w.write_all(self)?;
+        Ok(())
+    }
+}
+
+impl Readable for Vec<u8> {
+    fn read<R: Read>(r: &mut R) -> Result<Self, TapeError> {
+        let len = read_varint_from(r)? as usize;
+        let mut buf = vec![0u8; len];
+        r.read_exact(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// Length-prefix a serializable value and stream it directly to `w`, computing the length via
+/// `bincode::serialized_size` so the encoding is produced exactly once instead of being
+/// buffered into a throwaway `Vec` purely to learn its length.
+pub fn write_streamed<T: Serialize, W: Write>(w: &mut W, value: &T) -> Result<(), TapeError> {
+    let opts = bincode::options().with_big_endian();
+    let size = opts.serialized_size(value)?;
+    write_varint_to(w, size)?;
+    opts.serialize_into(w, value)?;
+    Ok(())
+}
+
+/// Read the next LEB128 varint from `r`, returning `Ok(None)` if the reader is already at end
+/// of stream (a clean record boundary) and `Ok(Some(_))` otherwise.
+fn read_varint_opt<R: Read>(r: &mut R) -> Result<Option<u64>, TapeError> {
+    let mut first = [0u8; 1];
+    // A zero-length read at the very start of a record means the stream ended cleanly.
+    if r.read(&mut first)? == 0 {
+        return Ok(None);
+    }
+    let mut value = (first[0] & 0x7f) as u64;
+    let mut shift = 0u32;
+    let mut byte = first[0];
+    // The first byte is already consumed, so at most `MAX_VARINT_BYTES - 1` may follow.
+    let mut seen = 1;
+    while byte & 0x80 != 0 {
+        if seen >= MAX_VARINT_BYTES {
+            return Err(TapeError::InvalidLength(MAX_VARINT_BYTES));
+        }
+        shift += 7;
+        let mut next = [0u8; 1];
+        r.read_exact(&mut next)?;
+        byte = next[0];
+        value |= ((byte & 0x7f) as u64) << shift;
+        seen += 1;
+    }
+    Ok(Some(value))
+}
+
+/// Write a TLV (type-length-value) stream: each record is `varint type || varint length ||
+/// value bytes`. Types must be supplied in strictly ascending order, matching the wire rule so
+/// readers can enforce it. Modeled on rust-lightning's TLV serialization.
+pub fn write_tlv_stream<W: Write>(
+    w: &mut W,
+    records: &[(u64, &dyn Writeable)],
+) -> Result<(), TapeError> {
+    let mut last: Option<u64> = None;
+    for (typ, value) in records {
+        if let Some(prev) = last {
+            if *typ <= prev {
+                return Err(TapeError::Parse);
+            }
+        }
+        last = Some(*typ);
+
+        // Encode the value into a scratch buffer so we can length-prefix it.
+        let mut body = Vec::new();
+        value.write(&mut body)?;
+
+        write_varint_to(w, *typ)?;
+        write_varint_to(w, body.len() as u64)?;
+        w.write_all(&body)?;
+    }
+    Ok(())
+}
+
+/// Parse a TLV stream from `r` into a map of type to raw value bytes, enforcing strictly
+/// ascending types and the even/odd rule: an unknown even type is mandatory and rejected, an
+/// unknown odd type is ignorable and its value skipped. `known` reports whether a given type is
+/// understood by the caller.
+pub fn read_tlv_stream<R: Read>(
+    r: &mut R,
+    known: impl Fn(u64) -> bool,
+) -> Result<std::collections::BTreeMap<u64, Vec<u8>>, TapeError> {
+    let mut out = std::collections::BTreeMap::new();
+    let mut last: Option<u64> = None;
+    while let Some(typ) = read_varint_opt(r)? {
+        if let Some(prev) = last {
+            if typ <= prev {
+                return Err(TapeError::Parse);
+            }
+        }
+        last = Some(typ);
+
+        let len = read_varint_from(r)? as usize;
+        let mut value = vec![0u8; len];
+        r.read_exact(&mut value)?;
+
+        if !known(typ) {
+            if typ % 2 == 0 {
+                // Unknown even type is mandatory: we cannot safely proceed.
+                return Err(TapeError::Parse);
+            }
+            // Unknown odd type is ignorable: drop the value and carry on.
+            continue;
+        }
+
+        out.insert(typ, value);
+    }
+    Ok(out)
+}
+
+/// Marker for plain-old-data types that may be reconstructed by reinterpreting raw tape bytes
+/// in place. Implementing this is an unsafe promise that the type is `#[repr(C)]` (or otherwise
+/// fixed-layout), contains no padding that would leak uninitialized memory, has no pointers or
+/// references, and is valid for every bit pattern of its size.
+///
+/// # Safety
+/// The invariants above must hold, or [`read_ref`] will produce an invalid value.
+pub unsafe trait Pod: Copy {}
+
+unsafe impl Pod for u8 {}
+unsafe impl Pod for u16 {}
+unsafe impl Pod for u32 {}
+unsafe impl Pod for u64 {}
+unsafe impl Pod for i32 {}
+unsafe impl Pod for i64 {}
+
+/// An owning, correctly-aligned buffer holding the raw bytes of a single tape-resident `T`,
+/// interpreted in place. Modeled on regex-automata's `wire.rs` zero-copy loading: the bytes are
+/// read once into an over-aligned allocation and then viewed as a `&T` with no owned copy of the
+/// decoded value and no second pass through bincode.
+pub struct TapeRef<T: Pod> {
+    /// Backing allocation; the live bytes start at `offset` and run for `size_of::<T>()`.
+    data: Vec<u8>,
+    offset: usize,
+    _marker: std::marker::PhantomData<T>,
+}
+
+impl<T: Pod> TapeRef<T> {
+    /// Borrow the buffer as a `&T`, validating alignment and length. Both hold by construction,
+    /// but we check defensively so a future change can't silently produce an unaligned read.
+    pub fn get(&self) -> Result<&T, TapeError> {
+        let size = std::mem::size_of::<T>();
+        let align = std::mem::align_of::<T>();
+        if self.data.len() < self.offset + size {
+            return Err(TapeError::InvalidLength(self.data.len()));
+        }
+        let ptr = unsafe { self.data.as_ptr().add(self.offset) };
+        if (ptr as usize) % align != 0 {
+            return Err(TapeError::InvalidLength(self.offset));
+        }
+        // SAFETY: `ptr` is aligned for `T` and the buffer holds at least `size` bytes starting
+        // there; `T: Pod` guarantees every bit pattern is a valid value.
+        Ok(unsafe { &*(ptr as *const T) })
+    }
+}
+
+/// Read a length-prefixed, fixed-size `T` off the input tape without constructing an owned value
+/// through bincode. The `n` bytes named by the prefix (at least `size_of::<T>()`; the first
+/// `size_of::<T>()` of them form the value) are read once into an over-aligned buffer and
+/// exposed as a borrowed [`TapeRef`], giving near-constant-time loading of large immutable inputs
+/// (verifier keys, precomputed group-element tables). Rejects a prefix smaller than the type or
+/// larger than [`DEFAULT_READ_LIMIT`].
+///
+/// # Endianness
+/// The bytes are reinterpreted in host-native byte order. This path therefore consumes only
+/// native-endian POD dumps written by the producer as a raw memory image; it is **not**
+/// compatible with [`write`]/[`write_streamed`] output, which bincode-encodes in big-endian.
+/// Reading a value framed by those functions back through `read_ref` on a little-endian prover
+/// yields a byte-swapped multi-byte field with no error.
+pub fn read_ref<T: Pod>() -> Result<TapeRef<T>, TapeError> {
+    read_ref_limited(DEFAULT_READ_LIMIT)
+}
+
+/// Like [`read_ref`], but rejects any prefix larger than `max` before allocating.
+pub fn read_ref_limited<T: Pod>(max: usize) -> Result<TapeRef<T>, TapeError> {
+    let size = std::mem::size_of::<T>();
+    let align = std::mem::align_of::<T>();
+
+    let n = read_varint()?;
+    if n > max {
+        return Err(TapeError::InvalidLength(n));
+    }
+    // The buffer must hold at least a whole `T`; a shorter prefix cannot be viewed in place.
+    if n < size {
+        return Err(TapeError::InvalidLength(n));
+    }
+
+    // Over-allocate by `align` so the live region can start at an aligned offset regardless of
+    // where the `Vec`'s own allocation lands.
+    let mut data = vec![0u8; n + align];
+    let base = data.as_ptr() as usize;
+    let offset = (align - (base % align)) % align;
+
+    // Read the bytes directly into the aligned region so no transient owned copy is made.
+    for slot in &mut data[offset..offset + n] {
+        *slot = getchar_opt().ok_or(TapeError::UnexpectedEof)?;
+    }
+
+    Ok(TapeRef {
+        data,
+        offset,
+        _marker: std::marker::PhantomData,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn varint_round_trips() {
+        for n in [0u64, 1, 127, 128, 300, 16_384, u32::MAX as u64, u64::MAX] {
+            let mut buf = Vec::new();
+            write_varint_to(&mut buf, n).unwrap();
+            let mut r = Cursor::new(buf);
+            assert_eq!(read_varint_from(&mut r).unwrap(), n);
+        }
+    }
+
+    #[test]
+    fn varint_rejects_overlong_continuation() {
+        // Eleven continuation bytes: longer than any valid u64 varint and enough to overrun the
+        // shift if left unbounded.
+        let bomb = vec![0x80u8; 11];
+        let mut r = Cursor::new(bomb);
+        assert!(matches!(
+            read_varint_from(&mut r),
+            Err(TapeError::InvalidLength(_))
+        ));
+    }
+
+    #[test]
+    fn tlv_stream_round_trips_known_types() {
+        let mut buf = Vec::new();
+        let a: u64 = 7;
+        let b: Vec<u8> = vec![1, 2, 3];
+        write_tlv_stream(&mut buf, &[(2, &a), (4, &b)]).unwrap();
+
+        let mut r = Cursor::new(buf);
+        let map = read_tlv_stream(&mut r, |_| true).unwrap();
+        assert_eq!(map.len(), 2);
+        assert_eq!(u64::read(&mut Cursor::new(map[&2].clone())).unwrap(), 7);
+        assert_eq!(Vec::<u8>::read(&mut Cursor::new(map[&4].clone())).unwrap(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn tlv_write_rejects_non_ascending_types() {
+        let mut buf = Vec::new();
+        let v: u8 = 0;
+        assert!(matches!(
+            write_tlv_stream(&mut buf, &[(4, &v), (2, &v)]),
+            Err(TapeError::Parse)
+        ));
+    }
+
+    #[test]
+    fn tlv_read_skips_unknown_odd_but_rejects_unknown_even() {
+        // Unknown odd type is ignorable and dropped from the map.
+        let mut buf = Vec::new();
+        let v: u8 = 9;
+        write_tlv_stream(&mut buf, &[(3, &v)]).unwrap();
+        let map = read_tlv_stream(&mut Cursor::new(buf), |_| false).unwrap();
+        assert!(map.is_empty());
+
+        // Unknown even type is mandatory and must error.
+        let mut buf = Vec::new();
+        write_tlv_stream(&mut buf, &[(4, &v)]).unwrap();
+        assert!(matches!(
+            read_tlv_stream(&mut Cursor::new(buf), |_| false),
+            Err(TapeError::Parse)
+        ));
+    }
+
+    #[test]
+    fn write_streamed_decodes_back_through_bincode() {
+        let mut buf = Vec::new();
+        let value: (u32, String) = (42, "voprf".to_string());
+        write_streamed(&mut buf, &value).unwrap();
+
+        // Strip the varint length prefix, then decode the remaining body big-endian.
+        let mut r = Cursor::new(buf);
+        let len = read_varint_from(&mut r).unwrap() as usize;
+        let mut body = vec![0u8; len];
+        r.read_exact(&mut body).unwrap();
+        let decoded: (u32, String) = bincode::options()
+            .with_big_endian()
+            .deserialize(&body)
+            .unwrap();
+        assert_eq!(decoded, value);
+    }
+
+    #[test]
+    fn tape_ref_rejects_misaligned_and_short_buffers() {
+        // A correctly aligned, full-size buffer yields the value.
+        let align = std::mem::align_of::<u32>();
+        let mut data = vec![0u8; std::mem::size_of::<u32>() + align];
+        let base = data.as_ptr() as usize;
+        let offset = (align - (base % align)) % align;
+        data[offset..offset + 4].copy_from_slice(&0x01020304u32.to_ne_bytes());
+        let good: TapeRef<u32> = TapeRef {
+            data,
+            offset,
+            _marker: std::marker::PhantomData,
+        };
+        assert_eq!(*good.get().unwrap(), 0x01020304);
+
+        // Misaligned: shift the live region one byte past the aligned offset.
+        let mut data = vec![0u8; std::mem::size_of::<u32>() + align + 1];
+        let base = data.as_ptr() as usize;
+        let aligned = (align - (base % align)) % align;
+        data.truncate(aligned + 1 + std::mem::size_of::<u32>());
+        let misaligned: TapeRef<u32> = TapeRef {
+            data,
+            offset: aligned + 1,
+            _marker: std::marker::PhantomData,
+        };
+        assert!(matches!(misaligned.get(), Err(TapeError::InvalidLength(_))));
+
+        // Too short for a whole `T`.
+        let short: TapeRef<u32> = TapeRef {
+            data: vec![0u8; 2],
+            offset: 0,
+            _marker: std::marker::PhantomData,
+        };
+        assert!(matches!(short.get(), Err(TapeError::InvalidLength(_))));
+    }
+}